@@ -0,0 +1,21 @@
+//! The four cardinal directions used to build [`Vector`](crate::vector::Vector)s
+//! and step between [`Location`](crate::location::Location)s.
+
+/// A single cardinal direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All four cardinal directions, in a fixed, stable order.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+}
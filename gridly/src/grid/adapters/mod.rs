@@ -0,0 +1,9 @@
+//! Zero-cost adapters that reinterpret an existing grid without copying its
+//! data: [`Transpose`] swaps rows and columns, and [`Window`] crops a
+//! rectangular sub-region.
+
+mod transpose;
+mod window;
+
+pub use transpose::Transpose;
+pub use window::Window;
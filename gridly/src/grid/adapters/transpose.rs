@@ -0,0 +1,58 @@
+use crate::grid::{BaseGrid, BaseGridMut, GridBounds};
+use crate::location::{Column, Location, Row};
+use crate::vector::{Columns, Rows};
+
+/// A grid adapter that swaps the rows and columns of an underlying grid.
+///
+/// `Transpose` is zero-cost: because the [`View`](crate::grid::view::View)
+/// and [`SingleView`](crate::grid::view::SingleView) machinery is generic
+/// over [`Component`](crate::location::Component), `grid.transpose().rows()`
+/// iterates the original grid's columns without copying any data.
+pub struct Transpose<G>(G);
+
+impl<G> Transpose<G> {
+    pub(crate) fn new(grid: G) -> Self {
+        Transpose(grid)
+    }
+
+    /// Unwrap this adapter, returning the original, non-transposed grid.
+    pub fn transpose(self) -> G {
+        self.0
+    }
+}
+
+fn swap(loc: &Location) -> Location {
+    Location::new(Row(loc.column.into()), Column(loc.row.into()))
+}
+
+impl<G: GridBounds> GridBounds for Transpose<G> {
+    fn root_row(&self) -> Row {
+        Row(self.0.root_column().into())
+    }
+
+    fn root_column(&self) -> Column {
+        Column(self.0.root_row().into())
+    }
+
+    fn num_rows(&self) -> Rows {
+        Rows(self.0.num_columns().into())
+    }
+
+    fn num_columns(&self) -> Columns {
+        Columns(self.0.num_rows().into())
+    }
+}
+
+impl<G: BaseGrid> BaseGrid for Transpose<G> {
+    type Item = G::Item;
+
+    unsafe fn get_unchecked(&self, loc: &Location) -> &Self::Item {
+        self.0.get_unchecked(&swap(loc))
+    }
+}
+
+impl<G: BaseGridMut> BaseGridMut for Transpose<G> {
+    unsafe fn get_unchecked_mut(&mut self, loc: &Location) -> &mut Self::Item {
+        self.0.get_unchecked_mut(&swap(loc))
+    }
+}
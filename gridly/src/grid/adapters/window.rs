@@ -0,0 +1,86 @@
+use crate::grid::{BaseGrid, BaseGridMut, BoundsError, GridBounds};
+use crate::location::{Location, Row, Column};
+use crate::vector::{Columns, Rows, Vector};
+
+/// A grid adapter presenting a rectangular sub-region of an underlying grid
+/// as a grid of its own, without copying any data.
+///
+/// A `Window` reports its own `(0, 0)`-rooted bounds matching its `size`, and
+/// translates incoming locations by its stored `corner` before delegating to
+/// the inner grid. This lets callers iterate `window.rows()` over a slice of
+/// a larger grid, crop regions, and compose with
+/// [`Transpose`](crate::grid::adapters::Transpose).
+pub struct Window<G> {
+    grid: G,
+    corner: Location,
+    size: Vector,
+}
+
+impl<G: GridBounds> Window<G> {
+    /// Create a new window over `grid`, rooted at `corner` with dimensions
+    /// `size`. Returns an error if the requested rectangle doesn't fit
+    /// entirely inside `grid`'s bounds.
+    pub fn new(
+        grid: G,
+        corner: impl Into<Location>,
+        size: impl Into<Vector>,
+    ) -> Result<Self, BoundsError> {
+        let corner = corner.into();
+        let size = size.into();
+        grid.check_location(corner)?;
+        // A zero-area window has no far corner to check; checking the corner
+        // above is enough to place it inside the grid's bounds. Skipping this
+        // also avoids underflowing `far_corner - (1, 1)` when the window sits
+        // at the grid's root.
+        if size.rows != Rows(0) && size.columns != Columns(0) {
+            let far_corner = corner + size;
+            // The far corner is exclusive, so back it up by one row and column
+            // before checking it's still in bounds.
+            grid.check_location(far_corner - Vector::new(1, 1))?;
+        }
+        Ok(Window { grid, corner, size })
+    }
+
+    /// Unwrap this adapter, returning the original, un-windowed grid.
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    fn translate(&self, loc: &Location) -> Location {
+        let offset = Vector::new(Rows(self.corner.row.into()), Columns(self.corner.column.into()));
+        *loc + offset
+    }
+}
+
+impl<G: GridBounds> GridBounds for Window<G> {
+    fn root_row(&self) -> Row {
+        Row(0)
+    }
+
+    fn root_column(&self) -> Column {
+        Column(0)
+    }
+
+    fn num_rows(&self) -> Rows {
+        self.size.rows
+    }
+
+    fn num_columns(&self) -> Columns {
+        self.size.columns
+    }
+}
+
+impl<G: BaseGrid> BaseGrid for Window<G> {
+    type Item = G::Item;
+
+    unsafe fn get_unchecked(&self, loc: &Location) -> &Self::Item {
+        self.grid.get_unchecked(&self.translate(loc))
+    }
+}
+
+impl<G: BaseGridMut> BaseGridMut for Window<G> {
+    unsafe fn get_unchecked_mut(&mut self, loc: &Location) -> &mut Self::Item {
+        let loc = self.translate(loc);
+        self.grid.get_unchecked_mut(&loc)
+    }
+}
@@ -0,0 +1,151 @@
+use derive_more::*;
+
+use crate::location::component::{ColumnRangeError, Range as ComponentRange, RangeError, RowRangeError};
+use crate::location::{Column, Component as LocComponent, Location, Row};
+use crate::vector::{Columns, Rows, Vector};
+
+/// Error indicating that a [`Location`] was outside of a grid's bounds.
+#[derive(Debug, Copy, Clone, From)]
+pub enum BoundsError {
+    Row(RowRangeError),
+    Column(ColumnRangeError),
+}
+
+/// High-level trait implementing grid sizes and boundary checking.
+///
+/// This trait doesn't provide any direct grid functionality, but instead
+/// provides the bounds checking which is generic to all of the different
+/// kinds of grid ([`BaseGrid`](crate::grid::BaseGrid), adapters, etc).
+pub trait GridBounds {
+    /// Return the index of the topmost row of this grid. For most grids,
+    /// this is 0, but some grids may include negatively indexed locations,
+    /// or even offsets. This value MUST be const for any given grid.
+    fn root_row(&self) -> Row {
+        Row(0)
+    }
+
+    /// Return the index of the leftmost column of this grid. For most grids,
+    /// this is 0, but some grids may include negatively indexed locations,
+    /// or even offsets. This value MUST be const for any given grid.
+    fn root_column(&self) -> Column {
+        Column(0)
+    }
+
+    /// Return the root location (ie, the top left) of the grid.
+    fn root(&self) -> Location {
+        Location::new(self.root_row(), self.root_column())
+    }
+
+    /// Get the height of the grid in [`Rows`]. This value MUST be const for
+    /// any given grid.
+    fn num_rows(&self) -> Rows;
+
+    /// Get the width of the grid, in [`Columns`]. This value MUST be const for
+    /// any given grid.
+    fn num_columns(&self) -> Columns;
+
+    /// Get the dimensions of the grid, as a [`Vector`].
+    fn dimensions(&self) -> Vector {
+        Vector::new(self.num_rows(), self.num_columns())
+    }
+
+    /// Check that a row is inside the bounds described by `root_row` and
+    /// `num_rows`.
+    fn check_row(&self, row: impl Into<Row>) -> Result<Row, RowRangeError> {
+        self.check_component(row.into())
+    }
+
+    /// Returns true if a row is inside the bounds described by `root_row` and
+    /// `num_rows`
+    fn row_in_bounds(&self, row: impl Into<Row>) -> bool {
+        self.check_row(row).is_ok()
+    }
+
+    /// Check that a column is inside the bounds described by `root_column`
+    /// and `num_columns`.
+    fn check_column(&self, column: impl Into<Column>) -> Result<Column, ColumnRangeError> {
+        self.check_component(column.into())
+    }
+
+    /// Returns true if a column is inside the bounds described by
+    /// `root_column` and `num_columns`
+    fn column_in_bounds(&self, column: impl Into<Column>) -> bool {
+        self.check_column(column).is_ok()
+    }
+
+    /// Check that a location is inside the bounds of this grid.
+    fn check_location(&self, loc: impl Into<Location>) -> Result<Location, BoundsError> {
+        let loc = loc.into();
+        self.check_row(loc.row)?;
+        self.check_column(loc.column)?;
+        Ok(loc)
+    }
+
+    /// Returns true if a location is inside the bounds of this grid.
+    fn location_in_bounds(&self, location: impl Into<Location>) -> bool {
+        self.check_location(location).is_ok()
+    }
+
+    /// Check that a [`Row`] or [`Column`] is within bounds, generically over
+    /// either component. This is what the [`View`](crate::grid::view::View)
+    /// and [`SingleView`](crate::grid::view::SingleView) machinery builds on.
+    fn check_component<T: LocComponent>(&self, value: T) -> Result<T, RangeError<T>> {
+        let min = T::root(self);
+        let min_index: isize = min.into();
+        let value_index: isize = value.into();
+
+        if value_index < min_index {
+            return Err(RangeError::TooLow(min));
+        }
+
+        let max_index = min_index + T::count(self).into();
+        if value_index >= max_index {
+            return Err(RangeError::TooHigh(T::from(max_index)));
+        }
+
+        Ok(value)
+    }
+
+    /// Get the full, in-bounds range of a [`Row`] or [`Column`] component.
+    fn range<T: LocComponent>(&self) -> ComponentRange<T> {
+        let min = T::root(self);
+        let max = T::from(min.into() + T::count(self).into());
+        ComponentRange::new(min, max)
+    }
+}
+
+impl<G: GridBounds + ?Sized> GridBounds for &G {
+    fn root_row(&self) -> Row {
+        (**self).root_row()
+    }
+
+    fn root_column(&self) -> Column {
+        (**self).root_column()
+    }
+
+    fn num_rows(&self) -> Rows {
+        (**self).num_rows()
+    }
+
+    fn num_columns(&self) -> Columns {
+        (**self).num_columns()
+    }
+}
+
+impl<G: GridBounds + ?Sized> GridBounds for &mut G {
+    fn root_row(&self) -> Row {
+        (**self).root_row()
+    }
+
+    fn root_column(&self) -> Column {
+        (**self).root_column()
+    }
+
+    fn num_rows(&self) -> Rows {
+        (**self).num_rows()
+    }
+
+    fn num_columns(&self) -> Columns {
+        (**self).num_columns()
+    }
+}
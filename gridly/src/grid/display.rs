@@ -0,0 +1,116 @@
+use std::fmt;
+use std::fmt::Display;
+
+use crate::grid::view::Grid;
+
+/// Extension trait adding aligned, table-style rendering to any grid whose
+/// cells implement [`Display`]. Mirrors the [`Grid`] blanket impl: any grid
+/// with a [`Display`] item gets this for free.
+pub trait PrettyPrint: Grid
+where
+    Self::Item: Display,
+{
+    /// Render this grid as a row-major table, with each column padded to the
+    /// width of its widest cell and separated by a single space.
+    fn to_pretty_string(&self) -> String {
+        self.render_pretty(" ", false)
+    }
+
+    /// Like [`to_pretty_string`](PrettyPrint::to_pretty_string), but prefixes
+    /// each row with its [`Row`](crate::location::Row) index and each column
+    /// with its [`Column`](crate::location::Column) index, derived from this
+    /// grid's `root`. This keeps negatively-indexed grids showing their true
+    /// coordinates rather than 0-based ones.
+    fn to_pretty_string_with_headers(&self) -> String {
+        self.render_pretty(" ", true)
+    }
+
+    /// Borrow this grid as a [`Display`]-able adapter, so it can be used
+    /// directly in `format!`/`println!` without first allocating a `String`.
+    fn pretty(&self) -> Pretty<Self>
+    where
+        Self: Sized,
+    {
+        Pretty(self)
+    }
+
+    #[doc(hidden)]
+    fn render_pretty(&self, separator: &str, headers: bool) -> String {
+        let row_header_width = if headers {
+            self.rows()
+                .iter()
+                .map(|row| row.index().0.to_string().len())
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
+        // First pass: compute the max formatted width of each column.
+        let widths: Vec<usize> = self
+            .columns()
+            .iter()
+            .map(|column| {
+                let header_width = if headers {
+                    column.index().0.to_string().len()
+                } else {
+                    0
+                };
+                column
+                    .iter()
+                    .map(|cell| cell.to_string().len())
+                    .max()
+                    .unwrap_or(0)
+                    .max(header_width)
+            })
+            .collect();
+
+        let mut out = String::new();
+
+        if headers {
+            // The row-header placeholder above takes the place of a first
+            // column, so every real column here gets a separator before it.
+            out.push_str(&" ".repeat(row_header_width));
+            for (column, width) in self.columns().iter().zip(&widths) {
+                out.push_str(separator);
+                out.push_str(&format!("{:>width$}", column.index().0, width = width));
+            }
+            out.push('\n');
+        }
+
+        // Second pass: emit each row, padded to the column widths computed above.
+        for row in self.rows().iter() {
+            // Without a row-header prefix, the first cell shouldn't get a
+            // leading separator; with one, it should (same reasoning as above).
+            let mut first = !headers;
+            if headers {
+                out.push_str(&format!("{:>width$}", row.index().0, width = row_header_width));
+            }
+            for (cell, width) in row.iter().zip(&widths) {
+                if !first {
+                    out.push_str(separator);
+                }
+                first = false;
+                out.push_str(&format!("{:>width$}", cell.to_string(), width = width));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl<G: Grid> PrettyPrint for G where G::Item: Display {}
+
+/// A [`Display`]-able view of a grid, produced by
+/// [`PrettyPrint::pretty`](PrettyPrint::pretty).
+pub struct Pretty<'a, G: Grid>(&'a G);
+
+impl<'a, G: Grid> fmt::Display for Pretty<'a, G>
+where
+    G::Item: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_pretty_string())
+    }
+}
@@ -0,0 +1,21 @@
+//! Traits and adapters for working with grids: bounds checking, row/column
+//! views, and mutable access.
+
+pub mod adapters;
+pub mod bounds;
+pub mod display;
+pub mod sparse;
+pub mod traverse;
+pub mod view;
+pub mod view_mut;
+
+pub use adapters::{Transpose, Window};
+pub use bounds::{BoundsError, GridBounds};
+pub use display::{Pretty, PrettyPrint};
+pub use sparse::SparseGrid;
+pub use traverse::FloodFill;
+pub use view::{BaseGrid, Grid, RowsView, ColumnsView, RowView, ColumnView, SingleView, View};
+pub use view_mut::{
+    BaseGridMut, ColumnViewMut, ColumnsViewMut, GridMut, RowViewMut, RowsViewMut, SingleViewMut,
+    ViewMut,
+};
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::grid::{BaseGrid, BaseGridMut, GridBounds};
+use crate::location::{Column, Location, Row};
+use crate::vector::{Columns, Rows, Vector};
+
+/// A grid that only stores cells which differ from a default value.
+///
+/// Cells that have never been written, or that have been written back to the
+/// default, are not present in the backing [`HashMap`] at all; reading them
+/// returns a reference to the stored default instead. This makes
+/// `SparseGrid` a good fit for large, mostly-empty grids.
+pub struct SparseGrid<T: PartialEq + Clone> {
+    root: Location,
+    dimensions: Vector,
+    default: T,
+    cells: HashMap<Location, T>,
+}
+
+impl<T: PartialEq + Clone> SparseGrid<T> {
+    /// Create a new, entirely-default `SparseGrid` with its root at the
+    /// origin.
+    pub fn new(dimensions: impl Into<Vector>, default: T) -> Self {
+        Self::new_rooted(Location::origin(), dimensions, default)
+    }
+
+    /// Create a new, entirely-default `SparseGrid` rooted at `root`.
+    pub fn new_rooted(root: impl Into<Location>, dimensions: impl Into<Vector>, default: T) -> Self {
+        SparseGrid {
+            root: root.into(),
+            dimensions: dimensions.into(),
+            default,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Iterate over the locations and values of the cells that are currently
+    /// stored, ie, that differ (or did differ) from the default value.
+    pub fn occupied_entries(&self) -> impl Iterator<Item = (Location, &T)> {
+        self.cells.iter().map(|(&loc, value)| (loc, value))
+    }
+
+    /// Iterate mutably over the locations and values of the cells that are
+    /// currently stored.
+    pub fn occupied_entries_mut(&mut self) -> impl Iterator<Item = (Location, &mut T)> {
+        self.cells.iter_mut().map(|(&loc, value)| (loc, value))
+    }
+
+    /// Remove every stored cell whose value is equal to the default,
+    /// reclaiming the memory they occupy.
+    pub fn clean(&mut self) {
+        let default = &self.default;
+        self.cells.retain(|_, value| value != default);
+    }
+}
+
+impl<T: PartialEq + Clone> GridBounds for SparseGrid<T> {
+    fn root_row(&self) -> Row {
+        self.root.row
+    }
+
+    fn root_column(&self) -> Column {
+        self.root.column
+    }
+
+    fn num_rows(&self) -> Rows {
+        self.dimensions.rows
+    }
+
+    fn num_columns(&self) -> Columns {
+        self.dimensions.columns
+    }
+}
+
+impl<T: PartialEq + Clone> BaseGrid for SparseGrid<T> {
+    type Item = T;
+
+    unsafe fn get_unchecked(&self, loc: &Location) -> &T {
+        self.cells.get(loc).unwrap_or(&self.default)
+    }
+}
+
+impl<T: PartialEq + Clone> BaseGridMut for SparseGrid<T> {
+    unsafe fn get_unchecked_mut(&mut self, loc: &Location) -> &mut T {
+        let default = &self.default;
+        self.cells.entry(*loc).or_insert_with(|| default.clone())
+    }
+}
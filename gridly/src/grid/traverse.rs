@@ -0,0 +1,59 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::grid::view::Grid;
+use crate::location::Location;
+
+/// Breadth-first traversal of the locations reachable from a starting point
+/// through cells matching a predicate, as returned by
+/// [`Grid::flood_fill`](crate::grid::view::Grid::flood_fill).
+pub struct FloodFill<'a, G: Grid + ?Sized, F> {
+    grid: &'a G,
+    predicate: F,
+    queue: VecDeque<Location>,
+    visited: HashSet<Location>,
+}
+
+impl<'a, G: Grid + ?Sized, F: Fn(&G::Item) -> bool> FloodFill<'a, G, F> {
+    pub(crate) fn new(grid: &'a G, start: Location, predicate: F) -> Self {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        // The start location is bounds-checked but not predicate-checked: if
+        // it's in bounds it's enqueued (and marked visited) unconditionally,
+        // matching the convention that flood_fill always includes its start.
+        if grid.location_in_bounds(start) {
+            visited.insert(start);
+            queue.push_back(start);
+        }
+        FloodFill {
+            grid,
+            predicate,
+            queue,
+            visited,
+        }
+    }
+}
+
+impl<'a, G: Grid + ?Sized, F: Fn(&G::Item) -> bool> Iterator for FloodFill<'a, G, F> {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        let current = self.queue.pop_front()?;
+
+        for neighbor in current.neighbors() {
+            if self.visited.contains(&neighbor) {
+                continue;
+            }
+            if let Ok(item) = self.grid.get(neighbor) {
+                if (self.predicate)(item) {
+                    // Mark as visited at enqueue time, not dequeue time, so a
+                    // cell reachable via two different paths is only ever
+                    // enqueued once.
+                    self.visited.insert(neighbor);
+                    self.queue.push_back(neighbor);
+                }
+            }
+        }
+
+        Some(current)
+    }
+}
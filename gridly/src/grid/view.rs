@@ -3,11 +3,14 @@ use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::ops::Index;
 
+use crate::grid::adapters::{Transpose, Window};
+use crate::grid::traverse::FloodFill;
 use crate::grid::{BoundsError, GridBounds};
 use crate::location::component::{
     ColumnRangeError, Range as IndexRange, RangeError, RowRangeError,
 };
 use crate::location::{Column, Component as LocComponent, Location, Range as LocationRange, Row};
+use crate::vector::Vector;
 
 pub trait BaseGrid: GridBounds {
     type Item;
@@ -92,6 +95,41 @@ pub trait Grid: BaseGrid {
     fn column(&self, column: impl Into<Column>) -> Result<ColumnView<Self>, ColumnRangeError> {
         self.single_view(column.into())
     }
+
+    /// Swap the rows and columns of this grid. Because the `View`/`SingleView`
+    /// machinery above is generic over [`Component`](LocComponent),
+    /// `grid.transpose().rows()` iterates the original grid's columns for
+    /// free.
+    fn transpose(self) -> Transpose<Self>
+    where
+        Self: Sized,
+    {
+        Transpose::new(self)
+    }
+
+    /// Get a window over a rectangular sub-region of this grid, rooted at
+    /// `corner` with dimensions `size`. Returns an error if the requested
+    /// rectangle doesn't fit entirely inside this grid's bounds.
+    fn window(
+        &self,
+        corner: impl Into<Location>,
+        size: impl Into<Vector>,
+    ) -> Result<Window<&Self>, BoundsError> {
+        Window::new(self, corner, size)
+    }
+
+    /// Breadth-first search from `start` over cells satisfying `predicate`,
+    /// yielding each accepted location (including `start` itself) as it's
+    /// discovered. Out-of-bounds neighbors are silently dropped rather than
+    /// causing a panic. Useful for region-filling and connected-component
+    /// labeling.
+    fn flood_fill<F: Fn(&Self::Item) -> bool>(
+        &self,
+        start: Location,
+        predicate: F,
+    ) -> FloodFill<Self, F> {
+        FloodFill::new(self, start, predicate)
+    }
 }
 
 impl<G: BaseGrid> Grid for G {}
@@ -1,9 +1,30 @@
+//! Mutable views into a [`Grid`], mirroring [`view`](crate::grid::view) but
+//! returning `&mut` access.
+//!
+//! Note one deliberate divergence from that mirroring: the immutable
+//! `View`/`SingleView` side returns `impl Iterator`, but `RowsCursorMut`,
+//! `CellsMut`, `WithLocationsMut`, and `WithComponentMut` do not implement
+//! [`Iterator`](std::iter::Iterator), so they don't support `for`,
+//! `.collect()`, `.map()`, etc. A real `Iterator<Item = &mut T>` here would
+//! let safe code hold several yielded references live at once, which is
+//! unsound against [`SparseGrid`](crate::grid::SparseGrid) (see the
+//! implementor notes below). Each type instead exposes an inherent,
+//! streaming-style `next`/`next_back`/`for_each`.
+
 use crate::grid::bounds::BoundsError;
 use crate::grid::view::Grid;
-use crate::location::{Location, LocationLike};
+use crate::location::component::{Range as IndexRange, RangeError};
+use crate::location::{Column, Component as LocComponent, Location, Range as LocationRange, Row};
 
 pub trait BaseGridMut: Grid {
     // TODO: try_get_unchecked_mut
+
+    /// Get a mutable reference to a cell, without doing bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Callers must ensure `location` is in bounds for this grid, per the
+    /// same contract as [`BaseGrid::get_unchecked`](crate::grid::BaseGrid::get_unchecked).
     unsafe fn get_unchecked_mut(&mut self, location: &Location) -> &mut Self::Item;
 }
 
@@ -13,15 +34,374 @@ impl<G: BaseGridMut> BaseGridMut for &mut G {
     }
 }
 
+/// Mutable view methods for a Grid, mirroring [`Grid`](crate::grid::view::Grid)
+/// but for `&mut` access.
 pub trait GridMut: BaseGridMut {
-    fn get_mut(&mut self, location: impl LocationLike) -> Result<&mut Self::Item, BoundsError> {
+    fn get_mut(&mut self, location: impl Into<Location>) -> Result<&mut Self::Item, BoundsError> {
         self.check_location(location)
             .map(move |loc| unsafe { self.get_unchecked_mut(&loc) })
     }
-}
 
-// TODO: mutable views, iterators
-// TODO: modify this trait to support extra behavior when references are dropped
-// (for instance, to allow clearing sparse grids)
+    /// Get a mutable view of a grid, over its rows or columns
+    fn view_mut<T: LocComponent>(&mut self) -> ViewMut<'_, Self, T> {
+        ViewMut::new(self)
+    }
+
+    /// Get a mutable view of a grid's rows
+    fn rows_mut(&mut self) -> RowsViewMut<'_, Self> {
+        self.view_mut()
+    }
+
+    /// Get a mutable view of a grid's columns
+    fn columns_mut(&mut self) -> ColumnsViewMut<'_, Self> {
+        self.view_mut()
+    }
+
+    /// Get a mutable view of a single row or column in a grid, without bounds
+    /// checking that row or column index.
+    ///
+    /// # Safety
+    ///
+    /// Callers must ensure `index` is in bounds for this grid.
+    unsafe fn single_view_mut_unchecked<T: LocComponent>(
+        &mut self,
+        index: T,
+    ) -> SingleViewMut<'_, Self, T> {
+        SingleViewMut::new_unchecked(self, index)
+    }
+
+    /// Get a mutable view of a single row in a grid, without bounds checking
+    /// that row's index
+    ///
+    /// # Safety
+    ///
+    /// Callers must ensure `row` is in bounds for this grid.
+    unsafe fn row_mut_unchecked(&mut self, row: impl Into<Row>) -> RowViewMut<'_, Self> {
+        self.single_view_mut_unchecked(row.into())
+    }
+
+    /// Get a mutable view of a single column in a grid, without bounds
+    /// checking that column's index
+    ///
+    /// # Safety
+    ///
+    /// Callers must ensure `column` is in bounds for this grid.
+    unsafe fn column_mut_unchecked(&mut self, column: impl Into<Column>) -> ColumnViewMut<'_, Self> {
+        self.single_view_mut_unchecked(column.into())
+    }
+
+    /// Get a mutable view of a single row or column in a grid. Returns an
+    /// error if the index of the row or column is out of bounds for the grid.
+    fn single_view_mut<T: LocComponent>(
+        &mut self,
+        index: T,
+    ) -> Result<SingleViewMut<'_, Self, T>, RangeError<T>> {
+        SingleViewMut::new(self, index)
+    }
+
+    /// Get a mutable view of a single row in a grid. Returns an error if the
+    /// index of the row is out of bounds for the grid.
+    fn row_mut(&mut self, row: impl Into<Row>) -> Result<RowViewMut<'_, Self>, RangeError<Row>> {
+        self.single_view_mut(row.into())
+    }
+
+    /// Get a mutable view of a single column in a grid. Returns an error if
+    /// the index of the column is out of bounds for the grid.
+    fn column_mut(
+        &mut self,
+        column: impl Into<Column>,
+    ) -> Result<ColumnViewMut<'_, Self>, RangeError<Column>> {
+        self.single_view_mut(column.into())
+    }
+}
 
 impl<G: BaseGridMut> GridMut for G {}
+
+// Implementor notes: every *Mut type below holds (or reborrows) a single
+// `&mut G` exclusive borrow of the underlying grid. Because `BaseGridMut`
+// makes no promise that a cell's address stays stable across calls to
+// `get_unchecked_mut` (`SparseGrid`, for instance, can reallocate its backing
+// `HashMap` on first mutable access to a cell), the only sound way to hand
+// out a `&mut G::Item` is to tie its lifetime to the `&mut self` call that
+// produced it, so the borrow checker guarantees at most one is live at a
+// time. That means these types can't implement the standard `Iterator`
+// trait (whose `Item` can't vary with the lifetime of each `next` call) —
+// instead they expose an inherent, streaming-style `next`/`next_back`, plus
+// a `for_each` for the common case of visiting every item in order.
+
+/// A mutable view of the rows or columns in a grid, mirroring
+/// [`View`](crate::grid::view::View).
+pub struct ViewMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    index: std::marker::PhantomData<T>,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> ViewMut<'a, G, T> {
+    fn new(grid: &'a mut G) -> Self {
+        Self {
+            grid,
+            index: std::marker::PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Callers must ensure `index` is in bounds for this grid.
+    pub unsafe fn get_unchecked_mut(&mut self, index: T) -> SingleViewMut<'_, G, T> {
+        SingleViewMut::new_unchecked(&mut *self.grid, index)
+    }
+
+    pub fn get_mut(
+        &mut self,
+        index: impl Into<T>,
+    ) -> Result<SingleViewMut<'_, G, T>, RangeError<T>> {
+        let index = index.into();
+        self.grid.check_component(index)?;
+        Ok(unsafe { self.get_unchecked_mut(index) })
+    }
+
+    pub fn range(&self) -> IndexRange<T> {
+        self.grid.range()
+    }
+
+    pub fn iter_mut(&mut self) -> RowsCursorMut<'_, G, T> {
+        let range = self.range();
+        RowsCursorMut {
+            grid: &mut *self.grid,
+            range,
+        }
+    }
+}
+
+pub type RowsViewMut<'a, G> = ViewMut<'a, G, Row>;
+pub type ColumnsViewMut<'a, G> = ViewMut<'a, G, Column>;
+
+/// Streaming cursor over the rows or columns of a [`ViewMut`], yielding one
+/// [`SingleViewMut`] at a time. Each yielded view borrows `&mut self`, so the
+/// previous one must be dropped before `next` can be called again — this is
+/// what rules out collecting several live views of the same grid at once.
+///
+/// Deliberately not an [`Iterator`](std::iter::Iterator): see the
+/// implementor notes above.
+pub struct RowsCursorMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    range: IndexRange<T>,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> RowsCursorMut<'a, G, T> {
+    // Not `std::iter::Iterator::next`: the whole point is that this item
+    // borrows `&mut self`, which that trait can't express.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<SingleViewMut<'_, G, T>> {
+        self.range
+            .next()
+            .map(|index| unsafe { SingleViewMut::new_unchecked(&mut *self.grid, index) })
+    }
+
+    pub fn next_back(&mut self) -> Option<SingleViewMut<'_, G, T>> {
+        self.range
+            .next_back()
+            .map(|index| unsafe { SingleViewMut::new_unchecked(&mut *self.grid, index) })
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.len() == 0
+    }
+
+    /// Visit every row/column in order, giving `f` a short-lived mutable view
+    /// of each one in turn.
+    pub fn for_each(&mut self, mut f: impl FnMut(SingleViewMut<'_, G, T>)) {
+        while let Some(view) = self.next() {
+            f(view);
+        }
+    }
+}
+
+/// A mutable view of a single row or column in a grid, mirroring
+/// [`SingleView`](crate::grid::view::SingleView).
+pub struct SingleViewMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    index: T,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> SingleViewMut<'a, G, T> {
+    unsafe fn new_unchecked(grid: &'a mut G, index: T) -> Self {
+        Self { grid, index }
+    }
+
+    fn new(grid: &'a mut G, index: T) -> Result<Self, RangeError<T>> {
+        grid.check_component(index)?;
+        Ok(unsafe { Self::new_unchecked(grid, index) })
+    }
+
+    pub fn index(&self) -> T {
+        self.index
+    }
+
+    /// # Safety
+    ///
+    /// Callers must ensure `cross` is in bounds for the grid's converse
+    /// component.
+    pub unsafe fn get_unchecked_mut(&mut self, cross: T::Converse) -> &mut G::Item {
+        let loc = self.index.combine(cross);
+        self.grid.get_unchecked_mut(&loc)
+    }
+
+    pub fn get_mut(
+        &mut self,
+        cross: impl Into<T::Converse>,
+    ) -> Result<&mut G::Item, RangeError<T::Converse>> {
+        let cross = self.grid.check_component(cross.into())?;
+        Ok(unsafe { self.get_unchecked_mut(cross) })
+    }
+
+    /// Get the locations associated with this view
+    pub fn range(&self) -> LocationRange<T> {
+        LocationRange::new(self.index, self.grid.range())
+    }
+
+    pub fn iter_mut(&mut self) -> CellsMut<'_, G, T> {
+        let range = self.range();
+        CellsMut {
+            grid: &mut *self.grid,
+            range,
+        }
+    }
+
+    pub fn with_locations_mut(&mut self) -> WithLocationsMut<'_, G, T> {
+        let range = self.range();
+        WithLocationsMut {
+            grid: &mut *self.grid,
+            range,
+        }
+    }
+
+    pub fn with_component_mut(&mut self) -> WithComponentMut<'_, G, T> {
+        let range = self.grid.range();
+        WithComponentMut {
+            grid: &mut *self.grid,
+            index: self.index,
+            range,
+        }
+    }
+}
+
+pub type RowViewMut<'a, G> = SingleViewMut<'a, G, Row>;
+pub type ColumnViewMut<'a, G> = SingleViewMut<'a, G, Column>;
+
+/// Streaming cursor over the cells of a [`SingleViewMut`], yielding
+/// `&mut Item` one at a time (see the implementor notes above for why this
+/// isn't a standard [`Iterator`]).
+pub struct CellsMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    range: LocationRange<T>,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> CellsMut<'a, G, T> {
+    // Not `std::iter::Iterator::next`: the whole point is that this item
+    // borrows `&mut self`, which that trait can't express.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&mut G::Item> {
+        let loc = self.range.next()?;
+        Some(unsafe { self.grid.get_unchecked_mut(&loc) })
+    }
+
+    pub fn next_back(&mut self) -> Option<&mut G::Item> {
+        let loc = self.range.next_back()?;
+        Some(unsafe { self.grid.get_unchecked_mut(&loc) })
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.len() == 0
+    }
+
+    pub fn for_each(&mut self, mut f: impl FnMut(&mut G::Item)) {
+        while let Some(item) = self.next() {
+            f(item);
+        }
+    }
+}
+
+/// Streaming cursor over the cells of a [`SingleViewMut`] paired with their
+/// [`Location`], yielding `(Location, &mut Item)` one at a time.
+pub struct WithLocationsMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    range: LocationRange<T>,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> WithLocationsMut<'a, G, T> {
+    // Not `std::iter::Iterator::next`: the whole point is that this item
+    // borrows `&mut self`, which that trait can't express.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(Location, &mut G::Item)> {
+        let loc = self.range.next()?;
+        Some((loc, unsafe { self.grid.get_unchecked_mut(&loc) }))
+    }
+
+    pub fn next_back(&mut self) -> Option<(Location, &mut G::Item)> {
+        let loc = self.range.next_back()?;
+        Some((loc, unsafe { self.grid.get_unchecked_mut(&loc) }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.len() == 0
+    }
+
+    pub fn for_each(&mut self, mut f: impl FnMut(Location, &mut G::Item)) {
+        while let Some((loc, item)) = self.next() {
+            f(loc, item);
+        }
+    }
+}
+
+/// Streaming cursor over the cells of a [`SingleViewMut`] paired with the
+/// converse component (the cell's row if this is a column view, or vice
+/// versa), yielding `(T::Converse, &mut Item)` one at a time.
+pub struct WithComponentMut<'a, G: GridMut + ?Sized, T: LocComponent> {
+    grid: &'a mut G,
+    index: T,
+    range: IndexRange<T::Converse>,
+}
+
+impl<'a, G: GridMut + ?Sized, T: LocComponent> WithComponentMut<'a, G, T> {
+    // Not `std::iter::Iterator::next`: the whole point is that this item
+    // borrows `&mut self`, which that trait can't express.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(T::Converse, &mut G::Item)> {
+        let cross = self.range.next()?;
+        let loc = cross.combine(self.index);
+        Some((cross, unsafe { self.grid.get_unchecked_mut(&loc) }))
+    }
+
+    pub fn next_back(&mut self) -> Option<(T::Converse, &mut G::Item)> {
+        let cross = self.range.next_back()?;
+        let loc = cross.combine(self.index);
+        Some((cross, unsafe { self.grid.get_unchecked_mut(&loc) }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.len() == 0
+    }
+
+    pub fn for_each(&mut self, mut f: impl FnMut(T::Converse, &mut G::Item)) {
+        while let Some((cross, item)) = self.next() {
+            f(cross, item);
+        }
+    }
+}
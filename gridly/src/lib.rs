@@ -0,0 +1,12 @@
+//! Gridly is a set of traits and utilities for working with 2D grids:
+//! vectors, locations, bounds-checked indexing, and the views built on top
+//! of them.
+
+pub mod direction;
+pub mod grid;
+pub mod location;
+pub mod vector;
+
+pub use direction::Direction;
+pub use location::{Column, Location, Row};
+pub use vector::{Columns, Rows, Vector};
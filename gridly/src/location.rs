@@ -0,0 +1,243 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use derive_more::*;
+
+use crate::direction::Direction;
+use crate::grid::GridBounds;
+use crate::vector::{Columns, Component as VecComponent, Rows, Vector};
+
+pub mod component;
+
+use component::Range as ComponentRange;
+
+/// A component of a [`Location`], either a [`Row`] or a [`Column`]
+pub trait Component: Sized + Copy + std::fmt::Debug + From<isize> + Into<isize> {
+    /// The converse component ([`Row`] to [`Column`], or vice versa)
+    type Converse: Component<Converse = Self>;
+
+    /// The associated vector component
+    type Distance: VecComponent;
+
+    /// Get this component type from a [`Location`]
+    fn from_location(location: &Location) -> Self;
+
+    /// Combine this component with its converse to create a [`Location`]
+    fn combine(self, other: Self::Converse) -> Location;
+
+    /// The name of this component ("row" or "column"), used in messages.
+    fn name() -> &'static str;
+
+    /// The root (minimum valid) value of this component in `grid`.
+    fn root<G: GridBounds + ?Sized>(grid: &G) -> Self;
+
+    /// The number of values this component spans in `grid`.
+    fn count<G: GridBounds + ?Sized>(grid: &G) -> Self::Distance;
+}
+
+macro_rules! make_component {
+    (
+        $Name:ident,
+        $Converse:ident,
+        $Distance:ident,
+        $from_loc:ident,
+        $root:ident,
+        $count:ident,
+        $name:expr,
+        ($self:ident, $other:ident) =>
+        ($first:ident, $second:ident)
+    ) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Into)]
+        #[repr(transparent)]
+        pub struct $Name(pub isize);
+
+        impl Add<$Converse> for $Name {
+            type Output = Location;
+
+            fn add(self, rhs: $Converse) -> Location {
+                self.combine(rhs)
+            }
+        }
+
+        impl<T: Into<$Distance>> Add<T> for $Name {
+            type Output = $Name;
+
+            fn add(self, rhs: T) -> Self {
+                $Name(self.0 + rhs.into().0)
+            }
+        }
+
+        impl<T: Into<$Distance>> AddAssign<T> for $Name {
+            fn add_assign(&mut self, rhs: T) {
+                self.0 += rhs.into().0
+            }
+        }
+
+        impl<T: Into<$Distance>> Sub<T> for $Name {
+            type Output = $Name;
+
+            fn sub(self, rhs: T) -> Self {
+                $Name(self.0 - rhs.into().0)
+            }
+        }
+
+        impl<T: Into<$Distance>> SubAssign<T> for $Name {
+            fn sub_assign(&mut self, rhs: T) {
+                self.0 -= rhs.into().0
+            }
+        }
+
+        impl Component for $Name {
+            type Converse = $Converse;
+            type Distance = $Distance;
+
+            fn from_location(loc: &Location) -> Self {
+                loc.$from_loc
+            }
+
+            fn combine($self, $other: Self::Converse) -> Location {
+                Location::new($first, $second)
+            }
+
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn root<G: GridBounds + ?Sized>(grid: &G) -> Self {
+                grid.$root()
+            }
+
+            fn count<G: GridBounds + ?Sized>(grid: &G) -> Self::Distance {
+                grid.$count()
+            }
+        }
+    };
+}
+
+make_component! {Row, Column, Rows, row, root_row, num_rows, "row", (self, other) => (self, other)}
+make_component! {Column, Row, Columns, column, root_column, num_columns, "column", (self, other) => (other, self)}
+
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct Location {
+    pub row: Row,
+    pub column: Column,
+}
+
+impl Location {
+    pub fn new(row: impl Into<Row>, column: impl Into<Column>) -> Self {
+        Location {
+            row: row.into(),
+            column: column.into(),
+        }
+    }
+
+    pub fn origin() -> Self {
+        Location::new(0, 0)
+    }
+
+    pub fn get_component<T: Component>(&self) -> T {
+        T::from_location(self)
+    }
+
+    pub fn above(&self, distance: impl Into<Rows>) -> Location {
+        *self - distance.into()
+    }
+
+    pub fn below(&self, distance: impl Into<Rows>) -> Location {
+        *self + distance.into()
+    }
+
+    pub fn left(&self, distance: impl Into<Columns>) -> Location {
+        *self - distance.into()
+    }
+
+    pub fn right(&self, distance: impl Into<Columns>) -> Location {
+        *self + distance.into()
+    }
+
+    pub fn relative(&self, direction: Direction, distance: isize) -> Location {
+        *self + Vector::in_direction(direction, distance)
+    }
+
+    /// Iterate over the four cells orthogonally adjacent to this one (one
+    /// step in each [`Direction`]).
+    pub fn neighbors(&self) -> impl Iterator<Item = Location> + '_ {
+        Direction::ALL.iter().map(move |&direction| self.relative(direction, 1))
+    }
+}
+
+impl<R: Into<Row>, C: Into<Column>> From<(R, C)> for Location {
+    fn from(value: (R, C)) -> Location {
+        Location::new(value.0, value.1)
+    }
+}
+
+impl<T: Into<Vector>> Add<T> for Location {
+    type Output = Location;
+
+    fn add(self, rhs: T) -> Location {
+        let rhs = rhs.into();
+        Location::new(self.row + rhs.rows, self.column + rhs.columns)
+    }
+}
+
+impl<T: Into<Vector>> AddAssign<T> for Location {
+    fn add_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        self.row += rhs.rows;
+        self.column += rhs.columns;
+    }
+}
+
+impl<T: Into<Vector>> Sub<T> for Location {
+    type Output = Location;
+
+    fn sub(self, rhs: T) -> Location {
+        let rhs = rhs.into();
+        Location::new(self.row - rhs.rows, self.column - rhs.columns)
+    }
+}
+
+impl<T: Into<Vector>> SubAssign<T> for Location {
+    fn sub_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        self.row -= rhs.rows;
+        self.column -= rhs.columns;
+    }
+}
+
+/// An iterator over every [`Location`] sharing a single fixed [`Row`] or
+/// [`Column`], produced by [`SingleView::range`](crate::grid::view::SingleView::range).
+#[derive(Debug, Clone)]
+pub struct Range<T: Component> {
+    index: T,
+    converse: ComponentRange<T::Converse>,
+}
+
+impl<T: Component> Range<T> {
+    pub(crate) fn new(index: T, converse: ComponentRange<T::Converse>) -> Self {
+        Range { index, converse }
+    }
+}
+
+impl<T: Component> Iterator for Range<T> {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        self.converse.next().map(|cross| self.index.combine(cross))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.converse.size_hint()
+    }
+}
+
+impl<T: Component> DoubleEndedIterator for Range<T> {
+    fn next_back(&mut self) -> Option<Location> {
+        self.converse
+            .next_back()
+            .map(|cross| self.index.combine(cross))
+    }
+}
+
+impl<T: Component> ExactSizeIterator for Range<T> {}
+impl<T: Component> std::iter::FusedIterator for Range<T> {}
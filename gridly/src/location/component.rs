@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+use std::ops::Range as StdRange;
+
+use super::Component;
+
+/// Error indicating that a [`Row`](super::Row) or [`Column`](super::Column)
+/// was out of bounds.
+///
+/// Note that the bounds expressed in this error are half inclusive; that is,
+/// the lower bound in `TooLow` is an inclusive lower bound, but the upper
+/// bound in `TooHigh` is an exclusive upper bound. This is consistent with
+/// the conventional range representation of `low..high`
+#[derive(Debug, Copy, Clone)]
+pub enum RangeError<T: Component> {
+    /// The given row or column was too low. The value in the error is the
+    /// minimum row or column, inclusive.
+    TooLow(T),
+
+    /// The given row or column was too high. The given value in the error is
+    /// the maximum row or column, exclusive (that is, a value *equal* to the
+    /// error value is considered too high).
+    TooHigh(T),
+}
+
+pub type RowRangeError = RangeError<super::Row>;
+pub type ColumnRangeError = RangeError<super::Column>;
+
+/// An iterator over the valid values of a single [`Component`] (a
+/// [`Row`](super::Row) or [`Column`](super::Column)) within a grid, as
+/// returned by [`GridBounds::range`](crate::grid::GridBounds::range).
+#[derive(Debug, Clone)]
+pub struct Range<T: Component> {
+    range: StdRange<isize>,
+    component: PhantomData<T>,
+}
+
+impl<T: Component> Range<T> {
+    pub(crate) fn new(low: T, high: T) -> Self {
+        Range {
+            range: low.into()..high.into(),
+            component: PhantomData,
+        }
+    }
+}
+
+impl<T: Component> Iterator for Range<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.range.next().map(T::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<T: Component> DoubleEndedIterator for Range<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.range.next_back().map(T::from)
+    }
+}
+
+impl<T: Component> ExactSizeIterator for Range<T> {}
+impl<T: Component> std::iter::FusedIterator for Range<T> {}
@@ -0,0 +1,136 @@
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use derive_more::*;
+
+use crate::direction::Direction;
+
+/// A component of a [`Vector`], either a [`Rows`] or a [`Columns`] distance.
+pub trait Component: Sized + Copy + std::fmt::Debug + From<isize> + Into<isize> {
+    /// The converse component ([`Rows`] to [`Columns`], or vice versa)
+    type Converse: Component<Converse = Self>;
+
+    /// Combine this component with its converse to create a [`Vector`]
+    fn combine(self, other: Self::Converse) -> Vector;
+}
+
+macro_rules! make_component {
+    (
+        $Name:ident, $Converse:ident,
+        ($self:ident, $other:ident) => ($first:ident, $second:ident)
+    ) => {
+        #[derive(
+            Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, From, Into,
+            Add, Sub, Neg, AddAssign, SubAssign,
+        )]
+        #[repr(transparent)]
+        pub struct $Name(pub isize);
+
+        impl Component for $Name {
+            type Converse = $Converse;
+
+            fn combine($self, $other: Self::Converse) -> Vector {
+                Vector {
+                    rows: $first,
+                    columns: $second,
+                }
+            }
+        }
+
+        impl Add<$Converse> for $Name {
+            type Output = Vector;
+
+            fn add(self, rhs: $Converse) -> Vector {
+                self.combine(rhs)
+            }
+        }
+    };
+}
+
+make_component! {Rows, Columns, (self, other) => (self, other)}
+make_component! {Columns, Rows, (self, other) => (other, self)}
+
+/// A displacement between two [`Location`](crate::location::Location)s, in rows and columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub rows: Rows,
+    pub columns: Columns,
+}
+
+impl Vector {
+    pub fn new(rows: impl Into<Rows>, columns: impl Into<Columns>) -> Self {
+        Vector {
+            rows: rows.into(),
+            columns: columns.into(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Vector::new(0, 0)
+    }
+
+    /// Build a unit-ish vector pointing `distance` steps in `direction`.
+    pub fn in_direction(direction: Direction, distance: isize) -> Self {
+        match direction {
+            Direction::Up => Vector::new(-distance, 0),
+            Direction::Down => Vector::new(distance, 0),
+            Direction::Left => Vector::new(0, -distance),
+            Direction::Right => Vector::new(0, distance),
+        }
+    }
+}
+
+impl<R: Into<Rows>, C: Into<Columns>> From<(R, C)> for Vector {
+    fn from(value: (R, C)) -> Vector {
+        Vector::new(value.0, value.1)
+    }
+}
+
+impl From<Rows> for Vector {
+    fn from(rows: Rows) -> Vector {
+        Vector::new(rows, 0)
+    }
+}
+
+impl From<Columns> for Vector {
+    fn from(columns: Columns) -> Vector {
+        Vector::new(0, columns)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.rows + rhs.rows, self.columns + rhs.columns)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Vector) {
+        self.rows += rhs.rows;
+        self.columns += rhs.columns;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector::new(self.rows - rhs.rows, self.columns - rhs.columns)
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        self.rows -= rhs.rows;
+        self.columns -= rhs.columns;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.rows, -self.columns)
+    }
+}